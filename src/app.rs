@@ -1,18 +1,34 @@
 use crate::middleware::auth::auth_middleware;
+use crate::publish::redis::RedisPublisher;
 use crate::{config::Config, routes::health::healthz, routes::telemetry::ingest_telemetry};
+use axum::extract::DefaultBodyLimit;
 use axum::{
     middleware,
     routing::{get, post},
     Router,
 };
+use arc_swap::ArcSwap;
 use sqlx::PgPool;
 use std::collections::HashMap;
+use std::sync::Arc;
+use tower_http::compression::CompressionLayer;
+use tower_http::decompression::RequestDecompressionLayer;
+
+/// Upper bound on a decompressed telemetry body. Inflating is capped here so a
+/// small `Content-Encoding: gzip` payload cannot expand into a memory-exhausting
+/// decompression bomb.
+const MAX_DECOMPRESSED_BYTES: usize = 4 * 1024 * 1024;
+
+/// Refreshable signal registry: an in-memory snapshot swapped atomically by a
+/// background task so registry edits take effect without a restart.
+pub type SignalRegistry = Arc<ArcSwap<HashMap<String, SignalKind>>>;
 
 #[derive(Clone)]
 pub struct AppState {
     pub cfg: Config,
     pub pool: PgPool,
-    pub signal_registry: HashMap<String, SignalKind>,
+    pub signal_registry: SignalRegistry,
+    pub redis: Option<RedisPublisher>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,24 +40,32 @@ pub enum SignalKind {
 pub fn build_router(
     cfg: Config,
     pool: PgPool,
-    signal_registry: HashMap<String, SignalKind>,
+    signal_registry: SignalRegistry,
+    redis: Option<RedisPublisher>,
 ) -> Router {
     let state = AppState {
         cfg: cfg.clone(),
         pool,
         signal_registry,
+        redis,
     };
 
     // Public routes (no auth)
     let public = Router::new().route("/healthz", get(healthz));
 
-    // Protected routes (with auth)
+    // Protected routes (with auth). Gzip request bodies are inflated before
+    // deserialization (capped at MAX_DECOMPRESSED_BYTES to guard against
+    // decompression bombs) and JSON responses are gzipped when the client
+    // advertises support via Accept-Encoding.
     let protected = Router::new()
         .route("/api/v1/telemetry", post(ingest_telemetry))
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             auth_middleware,
-        ));
+        ))
+        .layer(CompressionLayer::new())
+        .layer(RequestDecompressionLayer::new())
+        .layer(DefaultBodyLimit::max(MAX_DECOMPRESSED_BYTES));
 
     public.merge(protected).with_state(state)
 }
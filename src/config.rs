@@ -1,32 +1,77 @@
 use anyhow::{anyhow, Context, Result};
+use config::{Environment, File};
+use jsonwebtoken::Algorithm;
+use serde::Deserialize;
 use std::env;
 
-#[derive(Clone)]
+#[derive(Clone, Deserialize)]
 pub struct Config {
     pub database_url: String,
-    pub api_token: String,
+    pub jwt_secret: String,
+    #[serde(default = "default_algorithms")]
+    pub jwt_algorithms: Vec<Algorithm>,
+    #[serde(default)]
+    pub redis_url: Option<String>,
+    #[serde(default = "default_port")]
     pub port: u16,
+    #[serde(default = "default_registry_refresh_secs")]
+    pub registry_refresh_secs: u64,
 }
 
 impl Config {
+    /// Load configuration from a layered base + environment-specific TOML file,
+    /// then overlay environment variables so ops can override any field without
+    /// editing files.
+    ///
+    /// The environment-specific file is selected by `APP_ENV` (falling back to
+    /// `RUST_ENV`, then `development`), e.g. `config.production.toml`.
     pub fn from_env() -> Result<Self> {
-        let database_url =
-            env::var("DATABASE_URL").context("DATABASE_URL environment variable is required")?;
-        let api_token =
-            env::var("API_TOKEN").context("API_TOKEN environment variable is required")?;
-        let port = env::var("PORT")
-            .ok()
-            .and_then(|p| p.parse::<u16>().ok())
-            .unwrap_or(8080);
-
-        if api_token.trim().is_empty() {
-            return Err(anyhow!("API_TOKEN must not be empty"));
-        }
+        let env_name = env::var("APP_ENV")
+            .or_else(|_| env::var("RUST_ENV"))
+            .unwrap_or_else(|_| "development".to_string());
+
+        let settings = config::Config::builder()
+            .add_source(File::with_name("config").required(false))
+            .add_source(File::with_name(&format!("config.{env_name}")).required(false))
+            .add_source(
+                Environment::default()
+                    .try_parsing(true)
+                    .list_separator(",")
+                    .with_list_parse_key("jwt_algorithms"),
+            )
+            .build()
+            .context("failed to assemble configuration sources")?;
 
-        Ok(Self {
-            database_url,
-            api_token,
-            port,
-        })
+        let cfg: Config = settings
+            .try_deserialize()
+            .context("failed to parse configuration")?;
+
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.database_url.trim().is_empty() {
+            return Err(anyhow!("`database_url` must not be empty"));
+        }
+        if self.jwt_secret.trim().is_empty() {
+            return Err(anyhow!("`jwt_secret` must not be empty"));
+        }
+        if self.jwt_algorithms.is_empty() {
+            return Err(anyhow!("`jwt_algorithms` must list at least one algorithm"));
+        }
+        Ok(())
     }
 }
+
+fn default_algorithms() -> Vec<Algorithm> {
+    vec![Algorithm::HS256]
+}
+
+fn default_port() -> u16 {
+    8080
+}
+
+fn default_registry_refresh_secs() -> u64 {
+    60
+}
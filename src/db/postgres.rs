@@ -46,47 +46,59 @@ pub async fn vessel_exists(pool: &PgPool, vessel_id: &str) -> Result<bool> {
     Ok(exists)
 }
 
-pub async fn insert_raw(
+pub async fn insert_raw_batch(
     pool: &PgPool,
     vessel_id: &str,
     timestamp: chrono::DateTime<chrono::Utc>,
-    signal_name: &str,
-    signal_value: f64,
+    signals: &[(String, f64)],
 ) -> Result<()> {
+    if signals.is_empty() {
+        return Ok(());
+    }
+
+    let names: Vec<&str> = signals.iter().map(|(n, _)| n.as_str()).collect();
+    let values: Vec<f64> = signals.iter().map(|(_, v)| *v).collect();
+
     sqlx::query(
         r#"
         INSERT INTO main_raw (vessel_id, timestamp_utc, signal_name, signal_value)
-        VALUES ($1, $2, $3, $4)
+        SELECT $1, $2, * FROM UNNEST($3::text[], $4::float8[])
         "#,
     )
     .bind(vessel_id)
     .bind(timestamp)
-    .bind(signal_name)
-    .bind(signal_value)
+    .bind(&names)
+    .bind(&values)
     .execute(pool)
     .await?;
     Ok(())
 }
 
-pub async fn insert_filtered(
+pub async fn insert_filtered_batch(
     pool: &PgPool,
     vessel_id: &str,
     timestamp: chrono::DateTime<chrono::Utc>,
-    signal_name: &str,
-    signal_value: f64,
-    reason: &str,
+    signals: &[(String, f64, String)],
 ) -> Result<()> {
+    if signals.is_empty() {
+        return Ok(());
+    }
+
+    let names: Vec<&str> = signals.iter().map(|(n, _, _)| n.as_str()).collect();
+    let values: Vec<f64> = signals.iter().map(|(_, v, _)| *v).collect();
+    let reasons: Vec<&str> = signals.iter().map(|(_, _, r)| r.as_str()).collect();
+
     sqlx::query(
         r#"
         INSERT INTO filtered_raw (vessel_id, timestamp_utc, signal_name, signal_value, reason)
-        VALUES ($1, $2, $3, $4, $5)
+        SELECT $1, $2, * FROM UNNEST($3::text[], $4::float8[], $5::text[])
         "#,
     )
     .bind(vessel_id)
     .bind(timestamp)
-    .bind(signal_name)
-    .bind(signal_value)
-    .bind(reason)
+    .bind(&names)
+    .bind(&values)
+    .bind(&reasons)
     .execute(pool)
     .await?;
     Ok(())
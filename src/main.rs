@@ -8,6 +8,7 @@ mod config;
 mod db;
 mod middleware;
 mod models;
+mod publish;
 mod routes;
 
 #[tokio::main]
@@ -25,11 +26,55 @@ async fn main() -> anyhow::Result<()> {
     let pool = db::postgres::create_pool(&cfg.database_url).await?;
 
     // Preload signal registry into memory for fast validation
-    let signal_registry = db::postgres::load_signal_registry(&pool).await?;
-    info!(count = signal_registry.len(), "Loaded signal registry");
+    let initial_registry = db::postgres::load_signal_registry(&pool).await?;
+    info!(count = initial_registry.len(), "Loaded signal registry");
+    let signal_registry: app::SignalRegistry =
+        std::sync::Arc::new(arc_swap::ArcSwap::from_pointee(initial_registry));
+
+    // Refresh the registry periodically so edits to signal_register_table take
+    // effect without a restart.
+    {
+        let registry = signal_registry.clone();
+        let pool = pool.clone();
+        let interval_secs = cfg.registry_refresh_secs.max(1);
+        tokio::spawn(async move {
+            let mut ticker =
+                tokio::time::interval(std::time::Duration::from_secs(interval_secs));
+            ticker.tick().await; // consume the immediate first tick
+            loop {
+                ticker.tick().await;
+                match db::postgres::load_signal_registry(&pool).await {
+                    Ok(map) => {
+                        let count = map.len();
+                        registry.store(std::sync::Arc::new(map));
+                        info!(count, "Refreshed signal registry");
+                    }
+                    Err(e) => error!(error = %e, "Failed to refresh signal registry"),
+                }
+            }
+        });
+    }
+
+    // Optional Redis fan-out for real-time subscribers
+    let redis = match &cfg.redis_url {
+        Some(url) => match publish::redis::RedisPublisher::connect(url).await {
+            Ok(publisher) => {
+                info!("Connected to Redis for telemetry fan-out");
+                Some(publisher)
+            }
+            Err(e) => {
+                error!(error = %e, "Failed to connect to Redis; fan-out disabled");
+                None
+            }
+        },
+        None => {
+            info!("No REDIS_URL configured; telemetry fan-out disabled");
+            None
+        }
+    };
 
     // Build app router (in-process caching only)
-    let app: Router = app::build_router(cfg.clone(), pool.clone(), signal_registry);
+    let app: Router = app::build_router(cfg.clone(), pool.clone(), signal_registry, redis);
 
     // Bind address
     let addr = SocketAddr::from(([0, 0, 0, 0], cfg.port));
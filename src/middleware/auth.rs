@@ -3,25 +3,50 @@ use axum::extract::{Request, State};
 use axum::http::{header, StatusCode};
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
+use jsonwebtoken::{decode, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
 use tracing::warn;
 
-pub async fn auth_middleware(State(state): State<AppState>, req: Request, next: Next) -> Response {
+/// Claims carried by a vessel's bearer token. `sub` is the vessel id the token
+/// is authorized to submit telemetry for; `exp` is the Unix expiry timestamp.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Claims {
+    pub sub: String,
+    pub exp: usize,
+}
+
+/// The authenticated vessel id, stashed into request extensions by
+/// [`auth_middleware`] after a token has been validated.
+#[derive(Debug, Clone)]
+pub struct AuthenticatedVessel(pub String);
+
+pub async fn auth_middleware(State(state): State<AppState>, mut req: Request, next: Next) -> Response {
     let auth_header = req
         .headers()
         .get(header::AUTHORIZATION)
         .and_then(|h| h.to_str().ok())
         .unwrap_or("");
 
-    // Expect format: "Bearer <API_TOKEN>"
+    // Expect format: "Bearer <JWT>"
     let token = auth_header.strip_prefix("Bearer ").unwrap_or("");
     if token.is_empty() {
         warn!("Unauthorized request: missing Bearer token");
         return (StatusCode::UNAUTHORIZED, "Unauthorized").into_response();
     }
-    if token != state.cfg.api_token {
-        warn!("Unauthorized request: invalid API token");
-        return (StatusCode::UNAUTHORIZED, "Unauthorized").into_response();
-    }
+
+    let key = DecodingKey::from_secret(state.cfg.jwt_secret.as_bytes());
+    let mut validation = Validation::default();
+    validation.algorithms = state.cfg.jwt_algorithms.clone();
+
+    let claims = match decode::<Claims>(token, &key, &validation) {
+        Ok(data) => data.claims,
+        Err(e) => {
+            warn!(error = %e, "Unauthorized request: invalid or expired token");
+            return (StatusCode::UNAUTHORIZED, "Unauthorized").into_response();
+        }
+    };
+
+    req.extensions_mut().insert(AuthenticatedVessel(claims.sub));
 
     next.run(req).await
 }
@@ -18,9 +18,3 @@ impl TelemetryRequest {
         Ok(ts)
     }
 }
-
-#[derive(Debug)]
-pub struct ParsedSignal<'a> {
-    pub name: &'a str,
-    pub value: f64,
-}
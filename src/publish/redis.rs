@@ -0,0 +1,56 @@
+use anyhow::Result;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+use tracing::warn;
+
+/// Publishes validated readings onto per-vessel Redis streams so downstream
+/// subscribers can react to incoming telemetry without polling `main_raw`.
+///
+/// Publishing is best-effort: a Redis outage degrades to a logged warning
+/// rather than failing ingestion.
+#[derive(Clone)]
+pub struct RedisPublisher {
+    conn: ConnectionManager,
+}
+
+impl RedisPublisher {
+    /// Connect to Redis and build a multiplexed connection manager that
+    /// transparently reconnects.
+    pub async fn connect(url: &str) -> Result<Self> {
+        let client = redis::Client::open(url)?;
+        let conn = ConnectionManager::new(client).await?;
+        Ok(Self { conn })
+    }
+
+    /// Push a single reading onto `telemetry:{vessel_id}` without blocking the
+    /// ingestion path. Failures are logged and otherwise ignored.
+    pub fn publish_reading(
+        &self,
+        vessel_id: &str,
+        timestamp: chrono::DateTime<chrono::Utc>,
+        signal_name: &str,
+        value: f64,
+    ) {
+        let mut conn = self.conn.clone();
+        let stream = format!("telemetry:{vessel_id}");
+        let timestamp = timestamp.to_rfc3339();
+        let signal_name = signal_name.to_string();
+
+        tokio::spawn(async move {
+            let result: redis::RedisResult<String> = conn
+                .xadd(
+                    &stream,
+                    "*",
+                    &[
+                        ("timestamp", timestamp),
+                        ("signal", signal_name),
+                        ("value", value.to_string()),
+                    ],
+                )
+                .await;
+            if let Err(e) = result {
+                warn!(error = %e, stream = %stream, "Failed to publish reading to Redis");
+            }
+        });
+    }
+}
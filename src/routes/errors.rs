@@ -0,0 +1,66 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+use tracing::warn;
+
+/// Errors surfaced by the telemetry ingestion path, mapped to HTTP responses
+/// with a consistent machine-readable JSON envelope.
+#[derive(Debug)]
+pub enum IngestError {
+    /// The payload carried an empty or unparseable `timestampUTC`.
+    InvalidTimestamp,
+    /// The `vesselId` is unknown or the vessel is inactive.
+    UnknownVessel,
+    /// The `vesselId` does not match the authenticated token's subject.
+    VesselScopeMismatch,
+    /// The payload carried no signals to ingest.
+    EmptyPayload,
+    /// A database or other unexpected internal failure.
+    Database(anyhow::Error),
+}
+
+impl IngestError {
+    fn status(&self) -> StatusCode {
+        match self {
+            IngestError::InvalidTimestamp => StatusCode::BAD_REQUEST,
+            IngestError::UnknownVessel => StatusCode::FORBIDDEN,
+            IngestError::VesselScopeMismatch => StatusCode::FORBIDDEN,
+            IngestError::EmptyPayload => StatusCode::BAD_REQUEST,
+            IngestError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            IngestError::InvalidTimestamp => "Invalid timestampUTC",
+            IngestError::UnknownVessel => "Unknown or inactive vessel",
+            IngestError::VesselScopeMismatch => "vesselId does not match authenticated token",
+            IngestError::EmptyPayload => "Payload contained no signals",
+            IngestError::Database(_) => "Internal Server Error",
+        }
+    }
+}
+
+impl IntoResponse for IngestError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        if let IngestError::Database(e) = &self {
+            warn!(error = %e, "Internal error");
+        }
+        let body = Json(json!({
+            "status": status.as_u16(),
+            "message": self.message(),
+        }));
+        (status, body).into_response()
+    }
+}
+
+impl<E> From<E> for IngestError
+where
+    E: Into<anyhow::Error>,
+{
+    fn from(e: E) -> Self {
+        IngestError::Database(e.into())
+    }
+}
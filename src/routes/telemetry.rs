@@ -1,44 +1,55 @@
 use crate::app::{AppState, SignalKind};
-use crate::db::postgres::{insert_filtered, insert_metrics, insert_raw, vessel_exists};
-use crate::models::telemetry::{ParsedSignal, TelemetryRequest};
+use crate::db::postgres::{insert_filtered_batch, insert_metrics, insert_raw_batch, vessel_exists};
+use crate::middleware::auth::AuthenticatedVessel;
+use crate::models::telemetry::TelemetryRequest;
+use crate::routes::errors::IngestError;
 use axum::http::HeaderMap;
-use axum::{extract::State, http::StatusCode, Json};
+use axum::{extract::State, Extension, Json};
 use serde_json::json;
 use std::time::Instant;
-use tracing::{info, warn};
+use tracing::info;
 
 pub async fn ingest_telemetry(
     State(state): State<AppState>,
+    Extension(auth): Extension<AuthenticatedVessel>,
     _headers: HeaderMap,
     Json(payload): Json<TelemetryRequest>,
-) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+) -> Result<Json<serde_json::Value>, IngestError> {
     let total_start = Instant::now();
 
     // Extract vessel_id
     let vessel_id: String = payload.vesselId.clone();
 
+    // The token may only submit data for its own vessel
+    if vessel_id != auth.0 {
+        return Err(IngestError::VesselScopeMismatch);
+    }
+
     // Parse timestamp
     let ts = payload
         .parse_timestamp()
-        .map_err(|_| (StatusCode::BAD_REQUEST, "Invalid timestampUTC".to_string()))?;
+        .map_err(|_| IngestError::InvalidTimestamp)?;
 
     // Validate vessel exists
     let validation_start = Instant::now();
-    let exists = vessel_exists(&state.pool, &vessel_id)
-        .await
-        .map_err(internal_err)?;
+    let exists = vessel_exists(&state.pool, &vessel_id).await?;
     if !exists {
-        return Err((
-            StatusCode::FORBIDDEN,
-            "Unknown or inactive vessel".to_string(),
-        ));
+        return Err(IngestError::UnknownVessel);
+    }
+
+    if payload.signals.is_empty() {
+        return Err(IngestError::EmptyPayload);
     }
 
-    // Validate and categorize signals
-    let mut valid_signals: Vec<ParsedSignal> = Vec::new();
+    // Validate and categorize signals into two batches flushed with one round-trip each
+    let mut valid_signals: Vec<(String, f64)> = Vec::new();
+    let mut filtered_signals: Vec<(String, f64, String)> = Vec::new();
+
+    // Read the current registry snapshot; a background task swaps in refreshes.
+    let registry = state.signal_registry.load();
 
     for (name, value) in payload.signals.iter() {
-        let kind_opt = state.signal_registry.get(name).copied();
+        let kind_opt = registry.get(name).copied();
 
         match kind_opt {
             Some(kind) => {
@@ -57,62 +68,38 @@ pub async fn ingest_telemetry(
                             };
                             if v == 0 || v == 1 {
                                 let val_f = if v == 1 { 1.0 } else { 0.0 };
-                                valid_signals.push(ParsedSignal { name, value: val_f });
+                                valid_signals.push((name.clone(), val_f));
                             } else {
-                                let _ = insert_filtered(
-                                    &state.pool,
-                                    &vessel_id,
-                                    ts,
-                                    name,
+                                filtered_signals.push((
+                                    name.clone(),
                                     v as f64,
-                                    "out_of_range",
-                                )
-                                .await
-                                .map_err(internal_err)?;
+                                    "out_of_range".to_string(),
+                                ));
                             }
                         } else {
-                            let _ = insert_filtered(
-                                &state.pool,
-                                &vessel_id,
-                                ts,
-                                name,
+                            filtered_signals.push((
+                                name.clone(),
                                 f64::NAN,
-                                "type_mismatch",
-                            )
-                            .await
-                            .map_err(internal_err)?;
+                                "type_mismatch".to_string(),
+                            ));
                         }
                     }
                     // Analog signals: floats 1.0..=65535.0 only
                     (SignalKind::Analog, serde_json::Value::Number(n)) if n.is_f64() => {
                         let val_f = n.as_f64().unwrap_or(f64::NAN);
                         if (val_f >= 1.0) && (val_f <= 65535.0) {
-                            valid_signals.push(ParsedSignal { name, value: val_f });
+                            valid_signals.push((name.clone(), val_f));
                         } else {
-                            let _ = insert_filtered(
-                                &state.pool,
-                                &vessel_id,
-                                ts,
-                                name,
-                                val_f,
-                                "out_of_range",
-                            )
-                            .await
-                            .map_err(internal_err)?;
+                            filtered_signals.push((name.clone(), val_f, "out_of_range".to_string()));
                         }
                     }
                     // Anything else is a type mismatch (e.g., strings, bools, or integer for analog)
                     _ => {
-                        let _ = insert_filtered(
-                            &state.pool,
-                            &vessel_id,
-                            ts,
-                            name,
+                        filtered_signals.push((
+                            name.clone(),
                             f64::NAN,
-                            "type_mismatch",
-                        )
-                        .await
-                        .map_err(internal_err)?;
+                            "type_mismatch".to_string(),
+                        ));
                     }
                 }
             }
@@ -122,23 +109,27 @@ pub async fn ingest_telemetry(
                     serde_json::Value::Number(n) => n.as_f64().unwrap_or(f64::NAN),
                     _ => f64::NAN,
                 };
-                let _ = insert_filtered(&state.pool, &vessel_id, ts, name, val_f, "unknown_signal")
-                    .await
-                    .map_err(internal_err)?;
+                filtered_signals.push((name.clone(), val_f, "unknown_signal".to_string()));
             }
         }
     }
 
+    // Flush filtered signals in a single round-trip (part of the validation cost)
+    insert_filtered_batch(&state.pool, &vessel_id, ts, &filtered_signals).await?;
+
     let validation_ms = validation_start.elapsed().as_millis() as i64;
 
-    // Insert valid signals into main_raw
+    // Insert valid signals into main_raw with a single round-trip
     let ingestion_start = Instant::now();
-    for sig in &valid_signals {
-        insert_raw(&state.pool, &vessel_id, ts, sig.name, sig.value)
-            .await
-            .map_err(internal_err)?;
-    }
+    insert_raw_batch(&state.pool, &vessel_id, ts, &valid_signals).await?;
     let ingestion_ms = ingestion_start.elapsed().as_millis() as i64;
+
+    // Fan out committed readings to real-time subscribers (best-effort)
+    if let Some(redis) = &state.redis {
+        for (name, value) in &valid_signals {
+            redis.publish_reading(&vessel_id, ts, name, *value);
+        }
+    }
     let total_ms = total_start.elapsed().as_millis() as i64;
 
     // Record metrics
@@ -149,8 +140,7 @@ pub async fn ingest_telemetry(
         ingestion_ms,
         total_ms,
     )
-    .await
-    .map_err(internal_err)?;
+    .await?;
 
     info!(
         vessel_id,
@@ -170,11 +160,3 @@ pub async fn ingest_telemetry(
         "totalMs": total_ms,
     })))
 }
-
-fn internal_err<E: std::fmt::Display>(e: E) -> (StatusCode, String) {
-    warn!(error=%e, "Internal error");
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        "Internal Server Error".to_string(),
-    )
-}